@@ -22,19 +22,22 @@
 
 use std::{marker::PhantomData, sync::Arc};
 
-use codec::{Codec, Encode};
+use codec::{Codec, Decode, Encode};
 use jsonrpsee::{
 	core::async_trait,
 	proc_macros::rpc,
 	types::error::{CallError, ErrorObject},
 };
-use pallet_mmr_primitives::{Error as MmrError, Proof};
+use pallet_mmr_primitives::{EncodableOpaqueLeaf, Error as MmrError, Proof};
 use serde::{Deserialize, Serialize};
 
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_core::Bytes;
-use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, NumberFor},
+};
 
 pub use pallet_mmr_primitives::{LeafIndex, MmrApi as MmrRuntimeApi};
 
@@ -42,6 +45,7 @@ const RUNTIME_ERROR: i32 = 8000;
 const MMR_ERROR: i32 = 8010;
 const LEAF_NOT_FOUND_ERROR: i32 = MMR_ERROR + 1;
 const GENERATE_PROOF_ERROR: i32 = MMR_ERROR + 2;
+const DECODE_ERROR: i32 = MMR_ERROR + 3;
 
 type RpcResult<T> = std::result::Result<T, jsonrpsee::core::Error>;
 
@@ -68,9 +72,59 @@ impl<BlockHash> LeafProof<BlockHash> {
 	}
 }
 
+/// Retrieved MMR leaves and their proof.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeavesProof<BlockHash> {
+	/// Block hash the proof was generated for.
+	pub block_hash: BlockHash,
+	/// SCALE-encoded `Vec<EncodableOpaqueLeaf>`.
+	pub leaves: Bytes,
+	/// SCALE-encoded proof data. See [pallet_mmr_primitives::Proof].
+	pub proof: Bytes,
+}
+
+impl<BlockHash> LeavesProof<BlockHash> {
+	/// Create new `LeavesProof` from given concrete `leaves` and `proof`.
+	pub fn new<Leaves, MmrHash>(
+		block_hash: BlockHash,
+		leaves: Leaves,
+		proof: Proof<MmrHash>,
+	) -> Self
+	where
+		Leaves: Encode,
+		MmrHash: Encode,
+	{
+		Self { block_hash, leaves: Bytes(leaves.encode()), proof: Bytes(proof.encode()) }
+	}
+}
+
+/// Retrieved MMR ancestry proof.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AncestryProof<BlockHash> {
+	/// Block hash the proof was generated for.
+	pub block_hash: BlockHash,
+	/// SCALE-encoded ancestry proof data. See [pallet_mmr_primitives::AncestryProof].
+	pub proof: Bytes,
+}
+
+impl<BlockHash> AncestryProof<BlockHash> {
+	/// Create new `AncestryProof` from given concrete `proof`.
+	pub fn new<MmrHash>(
+		block_hash: BlockHash,
+		proof: pallet_mmr_primitives::AncestryProof<MmrHash>,
+	) -> Self
+	where
+		MmrHash: Encode,
+	{
+		Self { block_hash, proof: Bytes(proof.encode()) }
+	}
+}
+
 /// MMR RPC methods.
 #[rpc(client, server)]
-pub trait MmrApi<BlockHash> {
+pub trait MmrApi<BlockHash, MmrHash, BlockNumber> {
 	/// Generate MMR proof for given leaf index.
 	///
 	/// This method calls into a runtime with MMR pallet included and attempts to generate
@@ -85,6 +139,103 @@ pub trait MmrApi<BlockHash> {
 		leaf_index: LeafIndex,
 		at: Option<BlockHash>,
 	) -> RpcResult<LeafProof<BlockHash>>;
+
+	/// Get the MMR root hash for the current best block or, optionally, the given block.
+	///
+	/// This method calls into a runtime with MMR pallet included and retrieves the current
+	/// MMR root hash.
+	#[method(name = "mmr_root")]
+	fn mmr_root(&self, at: Option<BlockHash>) -> RpcResult<MmrHash>;
+
+	/// Get the number of MMR blocks (leaves) for the current best block or, optionally, the
+	/// given block.
+	///
+	/// This method calls into a runtime with MMR pallet included and retrieves the number of
+	/// leaves currently in the MMR.
+	#[method(name = "mmr_leafCount")]
+	fn mmr_leaf_count(&self, at: Option<BlockHash>) -> RpcResult<LeafIndex>;
+
+	/// Generate a single MMR proof covering several leaves at once, optionally anchored to a
+	/// historical MMR size.
+	///
+	/// This method calls into a runtime with MMR pallet included and attempts to generate
+	/// a single MMR proof for all the leaves at the given `leaf_indices`.
+	/// Optionally, a block hash at which the runtime should be queried can be specified.
+	///
+	/// When `best_known_block_number` is provided, the proof is generated against the MMR as
+	/// it existed at that block, rather than against the latest MMR state. The leaf count for
+	/// that historical MMR state is looked up from the block number; it is not assumed to equal
+	/// the block number itself, since a block does not necessarily append exactly one leaf.
+	/// This lets a verifier who only knows an older (e.g. finalized) MMR root still validate
+	/// the proof.
+	///
+	/// Returns the (full) leaves themselves and one proof for all of them (compact encoding,
+	/// i.e. hash of the leaves). Both parameters are SCALE-encoded.
+	#[method(name = "mmr_generateBatchProof")]
+	fn generate_batch_proof(
+		&self,
+		leaf_indices: Vec<LeafIndex>,
+		at: Option<BlockHash>,
+		best_known_block_number: Option<BlockNumber>,
+	) -> RpcResult<LeavesProof<BlockHash>>;
+
+	/// Verify an MMR `proof` against the on-chain MMR.
+	///
+	/// This method calls into a runtime with MMR pallet included and verifies the given
+	/// leaves/proof (as returned by [`Self::generate_batch_proof`]) against the MMR state
+	/// at the block hash recorded in the proof.
+	///
+	/// Returns `Ok(false)` if the proof simply does not verify; an `Err` indicates a runtime
+	/// failure, a decoding failure or any other MMR error rather than an invalid proof.
+	#[method(name = "mmr_verifyProof")]
+	fn verify_proof(&self, proof: LeavesProof<BlockHash>) -> RpcResult<bool>;
+
+	/// Verify an MMR `proof` against a given `mmr_root`, without consulting chain state.
+	///
+	/// This is useful for offchain tools and bridges that already hold a trusted MMR root
+	/// and only need to validate a proof blob against it.
+	///
+	/// Returns `Ok(false)` if the proof simply does not verify; an `Err` indicates a decoding
+	/// failure or any other MMR error rather than an invalid proof.
+	#[method(name = "mmr_verifyProofStateless")]
+	fn verify_proof_stateless(
+		&self,
+		mmr_root: MmrHash,
+		proof: LeavesProof<BlockHash>,
+	) -> RpcResult<bool>;
+
+	/// Generate an ancestry proof for the MMR at block `at` (or the best block), proving that
+	/// the MMR as it was when it had `prev_leaf_count` leaves is a prefix of the current MMR.
+	///
+	/// This lets light clients and bridges follow MMR root evolution without re-downloading
+	/// all the intervening leaves.
+	#[method(name = "mmr_generateAncestryProof")]
+	fn generate_ancestry_proof(
+		&self,
+		prev_leaf_count: LeafIndex,
+		at: Option<BlockHash>,
+	) -> RpcResult<AncestryProof<BlockHash>>;
+
+	/// Verify an ancestry `proof` against the on-chain MMR at the block hash recorded in the
+	/// proof.
+	///
+	/// Returns `Ok(false)` if the proof simply does not verify; an `Err` indicates a runtime
+	/// failure, a decoding failure or any other MMR error rather than an invalid proof.
+	#[method(name = "mmr_verifyAncestryProof")]
+	fn verify_ancestry_proof(&self, proof: AncestryProof<BlockHash>) -> RpcResult<bool>;
+
+	/// Verify an ancestry `proof` against the supplied `prev_root` and `mmr_root`, without
+	/// consulting chain state.
+	///
+	/// Returns `Ok(false)` if the proof simply does not verify; an `Err` indicates a decoding
+	/// failure or any other MMR error rather than an invalid proof.
+	#[method(name = "mmr_verifyAncestryProofStateless")]
+	fn verify_ancestry_proof_stateless(
+		&self,
+		prev_root: MmrHash,
+		mmr_root: MmrHash,
+		proof: AncestryProof<BlockHash>,
+	) -> RpcResult<bool>;
 }
 
 /// MMR RPC methods.
@@ -101,7 +252,7 @@ impl<C, B> MmrRpc<C, B> {
 }
 
 #[async_trait]
-impl<Client, Block, MmrHash> MmrApiServer<<Block as BlockT>::Hash>
+impl<Client, Block, MmrHash> MmrApiServer<<Block as BlockT>::Hash, MmrHash, NumberFor<Block>>
 	for MmrRpc<Client, (Block, MmrHash)>
 where
 	Block: BlockT,
@@ -128,6 +279,168 @@ where
 
 		Ok(LeafProof::new(block_hash, leaf, proof))
 	}
+
+	fn mmr_root(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<MmrHash> {
+		let api = self.client.runtime_api();
+		let block_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let root = api
+			.mmr_root_with_context(
+				&BlockId::hash(block_hash),
+				sp_core::ExecutionContext::OffchainCall(None),
+			)
+			.map_err(runtime_error_into_rpc_error)?
+			.map_err(mmr_error_into_rpc_error)?;
+
+		Ok(root)
+	}
+
+	fn mmr_leaf_count(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<LeafIndex> {
+		let api = self.client.runtime_api();
+		let block_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let leaf_count = api
+			.mmr_leaf_count_with_context(
+				&BlockId::hash(block_hash),
+				sp_core::ExecutionContext::OffchainCall(None),
+			)
+			.map_err(runtime_error_into_rpc_error)?
+			.map_err(mmr_error_into_rpc_error)?;
+
+		Ok(leaf_count)
+	}
+
+	fn generate_batch_proof(
+		&self,
+		leaf_indices: Vec<LeafIndex>,
+		at: Option<<Block as BlockT>::Hash>,
+		best_known_block_number: Option<NumberFor<Block>>,
+	) -> RpcResult<LeavesProof<Block::Hash>> {
+		let api = self.client.runtime_api();
+		let block_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		// `best_known_block_number` identifies a historical block, not a leaf count directly
+		// (a block does not necessarily append exactly one leaf), so look up how many leaves
+		// the MMR had at that block before anchoring the proof to it.
+		let best_known_leaf_count = best_known_block_number
+			.map(|block_number| {
+				api.mmr_leaf_count_with_context(
+					&BlockId::number(block_number),
+					sp_core::ExecutionContext::OffchainCall(None),
+				)
+				.map_err(runtime_error_into_rpc_error)?
+				.map_err(mmr_error_into_rpc_error)
+			})
+			.transpose()?;
+
+		let (leaves, proof) = api
+			.generate_batch_proof_with_context(
+				&BlockId::hash(block_hash),
+				sp_core::ExecutionContext::OffchainCall(None),
+				leaf_indices,
+				best_known_leaf_count,
+			)
+			.map_err(runtime_error_into_rpc_error)?
+			.map_err(mmr_error_into_rpc_error)?;
+
+		Ok(LeavesProof::new(block_hash, leaves, proof))
+	}
+
+	fn verify_proof(&self, proof: LeavesProof<Block::Hash>) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+
+		let leaves = Vec::<EncodableOpaqueLeaf>::decode(&mut &proof.leaves.0[..])
+			.map_err(decode_error_into_rpc_error)?;
+		let decoded_proof =
+			Decode::decode(&mut &proof.proof.0[..]).map_err(decode_error_into_rpc_error)?;
+
+		match api
+			.verify_proof_with_context(
+				&BlockId::hash(proof.block_hash),
+				sp_core::ExecutionContext::OffchainCall(None),
+				leaves,
+				decoded_proof,
+			)
+			.map_err(runtime_error_into_rpc_error)?
+		{
+			Ok(_) => Ok(true),
+			Err(MmrError::Verify) => Ok(false),
+			Err(err) => Err(mmr_error_into_rpc_error(err))?,
+		}
+	}
+
+	fn verify_proof_stateless(
+		&self,
+		mmr_root: MmrHash,
+		proof: LeavesProof<Block::Hash>,
+	) -> RpcResult<bool> {
+		let leaves = Vec::<EncodableOpaqueLeaf>::decode(&mut &proof.leaves.0[..])
+			.map_err(decode_error_into_rpc_error)?;
+		let decoded_proof =
+			Decode::decode(&mut &proof.proof.0[..]).map_err(decode_error_into_rpc_error)?;
+
+		match pallet_mmr_primitives::verify_leaves_proof(mmr_root, leaves, decoded_proof) {
+			Ok(_) => Ok(true),
+			Err(MmrError::Verify) => Ok(false),
+			Err(err) => Err(mmr_error_into_rpc_error(err))?,
+		}
+	}
+
+	fn generate_ancestry_proof(
+		&self,
+		prev_leaf_count: LeafIndex,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<AncestryProof<Block::Hash>> {
+		let api = self.client.runtime_api();
+		let block_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let proof = api
+			.generate_ancestry_proof_with_context(
+				&BlockId::hash(block_hash),
+				sp_core::ExecutionContext::OffchainCall(None),
+				prev_leaf_count,
+			)
+			.map_err(runtime_error_into_rpc_error)?
+			.map_err(mmr_error_into_rpc_error)?;
+
+		Ok(AncestryProof::new(block_hash, proof))
+	}
+
+	fn verify_ancestry_proof(&self, proof: AncestryProof<Block::Hash>) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+
+		let decoded_proof =
+			Decode::decode(&mut &proof.proof.0[..]).map_err(decode_error_into_rpc_error)?;
+
+		match api
+			.verify_ancestry_proof_with_context(
+				&BlockId::hash(proof.block_hash),
+				sp_core::ExecutionContext::OffchainCall(None),
+				decoded_proof,
+			)
+			.map_err(runtime_error_into_rpc_error)?
+		{
+			Ok(_) => Ok(true),
+			Err(MmrError::Verify) => Ok(false),
+			Err(err) => Err(mmr_error_into_rpc_error(err))?,
+		}
+	}
+
+	fn verify_ancestry_proof_stateless(
+		&self,
+		prev_root: MmrHash,
+		mmr_root: MmrHash,
+		proof: AncestryProof<Block::Hash>,
+	) -> RpcResult<bool> {
+		let decoded_proof =
+			Decode::decode(&mut &proof.proof.0[..]).map_err(decode_error_into_rpc_error)?;
+
+		match pallet_mmr_primitives::verify_ancestry_proof(prev_root, mmr_root, decoded_proof) {
+			Ok(_) => Ok(true),
+			Err(MmrError::Verify) => Ok(false),
+			Err(err) => Err(mmr_error_into_rpc_error(err))?,
+		}
+	}
 }
 
 /// Converts a mmr-specific error into a [`CallError`].
@@ -157,6 +470,15 @@ fn runtime_error_into_rpc_error(err: impl std::fmt::Debug) -> CallError {
 	))
 }
 
+/// Converts a SCALE decoding error into a [`CallError`].
+fn decode_error_into_rpc_error(err: codec::Error) -> CallError {
+	CallError::Custom(ErrorObject::owned(
+		DECODE_ERROR,
+		"Failed to decode SCALE-encoded proof",
+		Some(format!("{:?}", err)),
+	))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -210,4 +532,44 @@ mod tests {
 		// then
 		assert_eq!(actual, expected);
 	}
+
+	#[test]
+	fn should_round_trip_leaves_proof() {
+		// given
+		let leaves = vec![vec![1_u8, 2, 3, 4], vec![5_u8, 6, 7, 8]];
+		let proof = Proof {
+			leaf_index: 1,
+			leaf_count: 9,
+			items: vec![H256::repeat_byte(1), H256::repeat_byte(2)],
+		};
+
+		let leaves_proof = LeavesProof::new(H256::repeat_byte(0), leaves, proof);
+
+		// when
+		let serialized = serde_json::to_string(&leaves_proof).unwrap();
+		let deserialized: LeavesProof<H256> = serde_json::from_str(&serialized).unwrap();
+
+		// then
+		assert_eq!(deserialized, leaves_proof);
+	}
+
+	#[test]
+	fn should_round_trip_ancestry_proof() {
+		// given
+		let proof = pallet_mmr_primitives::AncestryProof {
+			prev_peaks: vec![H256::repeat_byte(1), H256::repeat_byte(2)],
+			prev_leaf_count: 5,
+			leaf_count: 9,
+			items: vec![(3, H256::repeat_byte(3)), (7, H256::repeat_byte(4))],
+		};
+
+		let ancestry_proof = AncestryProof::new(H256::repeat_byte(0), proof);
+
+		// when
+		let serialized = serde_json::to_string(&ancestry_proof).unwrap();
+		let deserialized: AncestryProof<H256> = serde_json::from_str(&serialized).unwrap();
+
+		// then
+		assert_eq!(deserialized, ancestry_proof);
+	}
 }